@@ -0,0 +1,43 @@
+//! Generates `InferenceError` (see `src/error.rs`) from the `ov_status_e_*` constants in
+//! `openvino-sys`'s bindgen output, so the enum's variants can never drift from the status codes
+//! the installed OpenVINO release actually defines.
+//!
+//! The parsing/codegen logic itself lives in `src/codegen.rs` (included below via `#[path]`)
+//! rather than here, so its unit tests run under the crate's own `cargo test` instead of being
+//! silently skipped the way tests inside a build script always are.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/codegen.rs"]
+mod codegen;
+
+fn main() {
+    let bindings_path = env::var("DEP_OPENVINO_SYS_BINDINGS").expect(
+        "openvino-sys must export the path to its bindgen output via `cargo:bindings=<path>`",
+    );
+    println!("cargo:rerun-if-changed={bindings_path}");
+
+    let bindings = fs::read_to_string(&bindings_path)
+        .unwrap_or_else(|e| panic!("failed to read bindgen output at {bindings_path}: {e}"));
+
+    let codes = codegen::parse_status_codes(&bindings);
+    assert!(
+        codes.iter().any(|c| c.name == "OK"),
+        "bindgen output at {bindings_path} did not contain `ov_status_e_OK`"
+    );
+
+    let generated = codegen::generate_inference_error(&codes);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set by cargo");
+    let dest = Path::new(&out_dir).join("inference_error.rs");
+    fs::write(&dest, generated).expect("failed to write generated InferenceError");
+
+    // Forward the OpenVINO version these bindings were generated against so `library.rs` can
+    // compare it to the version loaded at runtime without hand-maintaining it separately.
+    let bindings_version = env::var("DEP_OPENVINO_SYS_VERSION").expect(
+        "openvino-sys must export the OpenVINO version its bindings were generated against via `cargo:version=<value>`",
+    );
+    println!("cargo:rustc-env=OPENVINO_BINDINGS_VERSION={bindings_version}");
+}
@@ -0,0 +1,159 @@
+//! Parsing the `ov_status_e_*` bindgen constants and generating `InferenceError` from them.
+//!
+//! This logic is shared between `build.rs` (which runs it at build time via `#[path]` to produce
+//! the generated enum in `OUT_DIR`) and this crate's own test suite (which exercises the pure
+//! parsing/formatting helpers directly) — a plain `mod` in `build.rs` alone would never run under
+//! `cargo test`, since Cargo doesn't treat build scripts as a test target.
+
+/// One `ov_status_e_*` constant discovered in the bindgen output: its suffix (e.g.
+/// `NETWORK_NOT_READ`).
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct StatusCode {
+    pub name: String,
+}
+
+/// Extract every `pub const ov_status_e_<NAME>: ov_status_e = <value>;` line from the bindgen
+/// output.
+pub fn parse_status_codes(bindings: &str) -> Vec<StatusCode> {
+    let mut codes = Vec::new();
+    for line in bindings.lines() {
+        let Some(rest) = line.trim().strip_prefix("pub const ov_status_e_") else {
+            continue;
+        };
+        let Some((name, _)) = rest.split_once(':') else {
+            continue;
+        };
+        codes.push(StatusCode {
+            name: name.trim().to_string(),
+        });
+    }
+    codes
+}
+
+/// CamelCase a `SCREAMING_SNAKE_CASE` bindgen suffix into a Rust variant name, e.g.
+/// `NETWORK_NOT_READ` -> `NetworkNotRead`.
+///
+/// `ov_status_e_UNKNOW_EXCEPTION` is special-cased to `UnknownException`: the bindgen constant
+/// itself is missing the upstream "n", and mechanically CamelCasing it would silently rename the
+/// existing, correctly-spelled public variant out from under callers.
+pub fn camel_case(name: &str) -> String {
+    if name == "UNKNOW_EXCEPTION" {
+        return "UnknownException".to_string();
+    }
+
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => {
+                    first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
+                }
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Render the full `InferenceError` enum and its `from(error_code: i32)` conversion as Rust
+/// source, to be `include!`d from `src/error.rs`.
+pub fn generate_inference_error(codes: &[StatusCode]) -> String {
+    let mut variants = String::new();
+    let mut arms = String::new();
+
+    for code in codes {
+        if code.name == "OK" {
+            continue; // handled separately: `ov_status_e_OK` maps to `Ok(())`
+        }
+        let variant = camel_case(&code.name);
+        let human = code.name.to_lowercase().replace('_', " ");
+        variants.push_str(&format!(
+            "    #[error(\"{human}: ({{message}})\")]\n    {variant} {{ message: String }},\n"
+        ));
+        arms.push_str(&format!(
+            "            openvino_sys::ov_status_e_{name} => Err({variant} {{ message }}),\n",
+            name = code.name
+        ));
+    }
+
+    format!(
+        "/// Enumerate errors returned by the OpenVINO implementation. Generated by `build.rs` from\n\
+         /// the `ov_status_e` bindings in `openvino-sys`; see\n\
+         /// [`OvStatusCode`](https://docs.openvino.ai/2023.3/api/c_cpp_api/group__ov__base__c__api.html#_CPPv411ov_status_e).\n\
+         #[allow(missing_docs)]\n\
+         #[derive(Debug, Error, PartialEq, Eq)]\n\
+         pub enum InferenceError {{\n\
+         {variants}    #[error(\"undefined error code: {{0}}\")]\n    Undefined(i32),\n\
+         }}\n\
+         \n\
+         impl InferenceError {{\n\
+         \x20\x20\x20\x20/// Convert an `error_code` to a [`Result`]:\n\
+         \x20\x20\x20\x20/// - `0` becomes `Ok`\n\
+         \x20\x20\x20\x20/// - anything else becomes `Err` containing an [`InferenceError`]\n\
+         \x20\x20\x20\x20pub fn from(error_code: i32) -> Result<(), InferenceError> {{\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20#[allow(clippy::enum_glob_use)]\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20use InferenceError::*;\n\
+         \n\
+         \x20\x20\x20\x20\x20\x20\x20\x20if error_code == openvino_sys::ov_status_e_OK {{\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20return Ok(());\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20}}\n\
+         \n\
+         \x20\x20\x20\x20\x20\x20\x20\x20let message = unsafe {{\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20std::ffi::CStr::from_ptr(openvino_sys::ov_get_last_err_msg())\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20.to_string_lossy()\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20\x20.into_owned()\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20}};\n\
+         \n\
+         \x20\x20\x20\x20\x20\x20\x20\x20match error_code {{\n\
+         {arms}            _ => Err(Undefined(error_code)),\n\
+         \x20\x20\x20\x20\x20\x20\x20\x20}}\n\
+         \x20\x20\x20\x20}}\n\
+         }}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{camel_case, parse_status_codes, StatusCode};
+
+    #[test]
+    fn camel_cases_multi_word_names() {
+        assert_eq!(camel_case("NETWORK_NOT_READ"), "NetworkNotRead");
+        assert_eq!(camel_case("GENERAL_ERROR"), "GeneralError");
+    }
+
+    #[test]
+    fn camel_cases_single_word_names() {
+        assert_eq!(camel_case("OK"), "Ok");
+    }
+
+    #[test]
+    fn special_cases_the_upstream_unknow_exception_typo() {
+        assert_eq!(camel_case("UNKNOW_EXCEPTION"), "UnknownException");
+    }
+
+    #[test]
+    fn parses_status_codes_from_bindgen_output() {
+        let bindings = "\
+            #[doc = \"some doc comment\"]\n\
+            pub const ov_status_e_OK: ov_status_e = 0;\n\
+            pub const ov_status_e_GENERAL_ERROR: ov_status_e = -1;\n\
+            pub const something_else: u32 = 1;\n\
+        ";
+        assert_eq!(
+            parse_status_codes(bindings),
+            vec![
+                StatusCode {
+                    name: "OK".to_string()
+                },
+                StatusCode {
+                    name: "GENERAL_ERROR".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_lines_that_are_not_status_constants() {
+        assert_eq!(parse_status_codes("// a comment\nlet x = 5;").len(), 0);
+    }
+}
@@ -1,92 +1,60 @@
+use std::path::PathBuf;
 use thiserror::Error;
 
-/// Enumerate errors returned by the OpenVINO implementation. See
-/// [`OvStatusCode`](https://docs.openvino.ai/2023.3/api/c_cpp_api/group__ov__base__c__api.html#_CPPv411ov_status_e).
-// TODO This could be auto-generated (https://github.com/intel/openvino-rs/issues/20).
-#[allow(missing_docs)]
-#[derive(Debug, Error, PartialEq, Eq)]
-pub enum InferenceError {
-    #[error("general error: ({message})")]
-    GeneralError { message: String },
-    #[error("not implemented: ({message})")]
-    NotImplemented { message: String },
-    #[error("network not loaded: ({message})")]
-    NetworkNotLoaded { message: String },
-    #[error("parameter mismatch: ({message})")]
-    ParameterMismatch { message: String },
-    #[error("not found: ({message})")]
-    NotFound { message: String },
-    #[error("out of bounds: ({message})")]
-    OutOfBounds { message: String },
-    #[error("unexpected: ({message})")]
-    Unexpected { message: String },
-    #[error("request busy: ({message})")]
-    RequestBusy { message: String },
-    #[error("result not ready: ({message})")]
-    ResultNotReady { message: String },
-    #[error("not allocated: ({message})")]
-    NotAllocated { message: String },
-    #[error("infer not started: ({message})")]
-    InferNotStarted { message: String },
-    #[error("network not read: ({message})")]
-    NetworkNotRead { message: String },
-    #[error("infer cancelled: ({message})")]
-    InferCancelled { message: String },
-    #[error("invalid c parameter: ({message})")]
-    InvalidCParam { message: String },
-    #[error("unknown C error: ({message})")]
-    UnknownCError { message: String },
-    #[error("not implemented C method: ({message})")]
-    NotImplementCMethod { message: String },
-    #[error("unknown exception: ({message})")]
-    UnknownException { message: String },
-    #[error("undefined error code: {0}")]
-    Undefined(i32),
-}
+// `InferenceError` and its `from(error_code: i32)` conversion are generated by `build.rs` from
+// the `ov_status_e_*` constants in `openvino-sys`'s bindgen output, so new variants introduced by
+// an OpenVINO release can never fall out of sync with this enum (see
+// https://github.com/intel/openvino-rs/issues/20).
+include!(concat!(env!("OUT_DIR"), "/inference_error.rs"));
 
 impl InferenceError {
-    /// Convert an `error_code` to a [`Result`]:
-    /// - `0` becomes `Ok`
-    /// - anything else becomes `Err` containing an [`InferenceError`]
-    pub fn from(error_code: i32) -> Result<(), InferenceError> {
-        #[allow(clippy::enum_glob_use)]
-        use InferenceError::*;
-
-        if error_code == openvino_sys::ov_status_e_OK {
-            return Ok(());
-        }
-
-        let message = unsafe {
-            std::ffi::CStr::from_ptr(openvino_sys::ov_get_last_err_msg())
-                .to_string_lossy()
-                .into_owned()
+    /// Classify a [`GeneralError`](InferenceError::GeneralError) whose message indicates that
+    /// OpenVINO could not find a frontend conversion extension for an operation in the model (for
+    /// example, the OpenVINO Tokenizers extension required by many text/LLM models). Returns
+    /// `None` for any other error, including a `GeneralError` whose message doesn't match this
+    /// pattern, so callers can distinguish "your model needs an extension installed" from an
+    /// ordinary runtime error instead of string-matching the message themselves.
+    #[must_use]
+    pub fn as_unsupported_operation(&self) -> Option<UnsupportedOperation> {
+        let InferenceError::GeneralError { message } = self else {
+            return None;
         };
 
-        match error_code {
-            openvino_sys::ov_status_e_GENERAL_ERROR => Err(GeneralError { message }),
-            openvino_sys::ov_status_e_NOT_IMPLEMENTED => Err(NotImplemented { message }),
-            openvino_sys::ov_status_e_NETWORK_NOT_LOADED => Err(NetworkNotLoaded { message }),
-            openvino_sys::ov_status_e_PARAMETER_MISMATCH => Err(ParameterMismatch { message }),
-            openvino_sys::ov_status_e_NOT_FOUND => Err(NotFound { message }),
-            openvino_sys::ov_status_e_OUT_OF_BOUNDS => Err(OutOfBounds { message }),
-            openvino_sys::ov_status_e_UNEXPECTED => Err(Unexpected { message }),
-            openvino_sys::ov_status_e_REQUEST_BUSY => Err(RequestBusy { message }),
-            openvino_sys::ov_status_e_RESULT_NOT_READY => Err(ResultNotReady { message }),
-            openvino_sys::ov_status_e_NOT_ALLOCATED => Err(NotAllocated { message }),
-            openvino_sys::ov_status_e_INFER_NOT_STARTED => Err(InferNotStarted { message }),
-            openvino_sys::ov_status_e_NETWORK_NOT_READ => Err(NetworkNotRead { message }),
-            openvino_sys::ov_status_e_INFER_CANCELLED => Err(InferCancelled { message }),
-            openvino_sys::ov_status_e_INVALID_C_PARAM => Err(InvalidCParam { message }),
-            openvino_sys::ov_status_e_UNKNOWN_C_ERROR => Err(UnknownCError { message }),
-            openvino_sys::ov_status_e_NOT_IMPLEMENT_C_METHOD => {
-                Err(NotImplementCMethod { message })
-            }
-            openvino_sys::ov_status_e_UNKNOW_EXCEPTION => Err(UnknownException { message }),
-            _ => Err(Undefined(error_code)),
+        let lower = message.to_lowercase();
+        if !(lower.contains("no translator found") || lower.contains("conversion extension")) {
+            return None;
         }
+
+        Some(UnsupportedOperation {
+            message: message.clone(),
+            missing_extension: extract_quoted_name(message),
+        })
     }
 }
 
+/// A frontend conversion failure: OpenVINO recognized an operation in the model but has no
+/// translator for it without an external conversion extension. Produced by
+/// [`InferenceError::as_unsupported_operation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedOperation {
+    /// The original error message reported by OpenVINO.
+    pub message: String,
+    /// The name of the missing extension, if OpenVINO's message named one.
+    pub missing_extension: Option<String>,
+}
+
+/// Pull the last single-quoted name out of an OpenVINO frontend error message, if present.
+///
+/// Anchoring on the *last* quoted pair (rather than the first `'`) matters because these messages
+/// often contain an earlier, unpaired apostrophe in ordinary prose (e.g. "...but it's not
+/// registered; extension: 'openvino-tokenizers'") before the quotes that actually delimit the
+/// extension name.
+fn extract_quoted_name(message: &str) -> Option<String> {
+    let end = message.rfind('\'')?;
+    let start = message[..end].rfind('\'')?;
+    Some(message[start + 1..end].to_string())
+}
+
 /// Enumerate setup failures: in some cases, this library will call library-loading code that may
 /// fail in a different way (i.e., [`LoadingError`]) than the calls to the OpenVINO libraries (i.e.,
 /// [`InferenceError`]).
@@ -97,18 +65,134 @@ pub enum SetupError {
     Inference(#[from] InferenceError),
     #[error("library loading error")]
     Loading(#[from] LoadingError),
+    #[error("loaded OpenVINO library version ({found}) does not match the version this crate's bindings were generated against ({expected}); use `library::load` if you know the two are compatible anyway")]
+    VersionMismatch { expected: String, found: String },
 }
 
 /// Enumerate the ways that library loading can fail.
 #[allow(missing_docs)]
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum LoadingError {
     #[error("system failed to load shared libraries (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder): {0}")]
     SystemFailure(String),
-    #[error("cannot find path to shared libraries (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder)")]
-    CannotFindLibraryPath,
-    #[error("cannot find path to XML plugin configuration (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder)")]
-    CannotFindPluginPath,
+    #[error("cannot find path to shared libraries; {0}")]
+    CannotFindLibraryPath(SearchDiagnostics),
+    #[error("cannot find path to XML plugin configuration; {0}")]
+    CannotFindPluginPath(SearchDiagnostics),
     #[error("unable to convert path to a UTF-8 string (see https://doc.rust-lang.org/std/path/struct.Path.html#method.to_str)")]
     CannotStringifyPath,
 }
+
+/// The diagnostic context gathered by `openvino-finder` while searching for a required shared
+/// library or plugin configuration file: every directory it probed, the environment variables it
+/// consulted (and what it found there, if anything), and the filenames it looked for. Attached to
+/// the [`LoadingError`] variants that report a failed search so a user can see exactly why every
+/// candidate was rejected instead of re-deriving the finder's logic by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchDiagnostics {
+    /// The directories that were probed, in the order they were checked.
+    pub searched_dirs: Vec<PathBuf>,
+    /// The filenames that were searched for in each directory.
+    pub searched_filenames: Vec<String>,
+    /// The environment variables consulted, paired with the value found (if set).
+    pub checked_env_vars: Vec<(String, Option<String>)>,
+}
+
+impl From<openvino_finder::SearchError> for SearchDiagnostics {
+    /// `openvino-finder` walks the install layouts and gathers this diagnostic context itself;
+    /// this just renames its report into the type `LoadingError` carries, since `openvino-finder`
+    /// sits below `openvino` and can't name a type this crate owns.
+    fn from(err: openvino_finder::SearchError) -> Self {
+        SearchDiagnostics {
+            searched_dirs: err.searched_dirs,
+            searched_filenames: err.searched_filenames,
+            checked_env_vars: err.checked_env_vars,
+        }
+    }
+}
+
+impl std::fmt::Display for SearchDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "searched the following locations:")?;
+        for dir in &self.searched_dirs {
+            writeln!(f, "  - {}", dir.display())?;
+        }
+        writeln!(f, "for any of the following files:")?;
+        for filename in &self.searched_filenames {
+            writeln!(f, "  - {filename}")?;
+        }
+        write!(f, "after consulting these environment variables:")?;
+        for (var, value) in &self.checked_env_vars {
+            match value {
+                Some(value) => write!(f, "\n  - {var}={value}")?,
+                None => write!(f, "\n  - {var} (not set)")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_quoted_name, InferenceError, UnsupportedOperation};
+
+    #[test]
+    fn extracts_quoted_name_when_present() {
+        assert_eq!(
+            extract_quoted_name("requires the 'openvino-tokenizers' extension"),
+            Some("openvino-tokenizers".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_quoted_name_is_none_without_quotes() {
+        assert_eq!(extract_quoted_name("no translator found for op"), None);
+    }
+
+    #[test]
+    fn extract_quoted_name_ignores_earlier_unpaired_apostrophes() {
+        assert_eq!(
+            extract_quoted_name(
+                "but it's not registered; extension: 'openvino-tokenizers'"
+            ),
+            Some("openvino-tokenizers".to_string())
+        );
+    }
+
+    #[test]
+    fn classifies_missing_extension_messages() {
+        let err = InferenceError::GeneralError {
+            message: "No translator found for op Foo, and FrontEnd provides conversion \
+                      extension for it, but it's not registered; extension: 'openvino-tokenizers'"
+                .to_string(),
+        };
+        assert_eq!(
+            err.as_unsupported_operation().unwrap().missing_extension,
+            Some("openvino-tokenizers".to_string())
+        );
+    }
+
+    #[test]
+    fn does_not_classify_unrelated_general_errors() {
+        let err = InferenceError::GeneralError {
+            message: "some unrelated failure".to_string(),
+        };
+        assert_eq!(err.as_unsupported_operation(), None);
+    }
+
+    #[test]
+    fn does_not_classify_non_general_errors() {
+        let err = InferenceError::Undefined(42);
+        assert_eq!(err.as_unsupported_operation(), None);
+    }
+
+    #[test]
+    fn unsupported_operation_is_equatable() {
+        let a = UnsupportedOperation {
+            message: "m".to_string(),
+            missing_extension: None,
+        };
+        let b = a.clone();
+        assert_eq!(a, b);
+    }
+}
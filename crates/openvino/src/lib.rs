@@ -0,0 +1,7 @@
+//! High-level, safe bindings to OpenVINO.
+
+pub mod error;
+pub mod library;
+
+#[path = "codegen.rs"]
+mod codegen;
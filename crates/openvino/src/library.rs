@@ -0,0 +1,106 @@
+//! Loading the OpenVINO shared libraries.
+
+use crate::error::{InferenceError, LoadingError, SearchDiagnostics, SetupError};
+use std::sync::OnceLock;
+
+/// The `MAJOR.MINOR` OpenVINO API version this crate's bindings were generated against, forwarded
+/// by `build.rs` from `openvino-sys`'s exported version metadata so it can never drift from the
+/// bindings it's compared against.
+const BINDINGS_VERSION: &str = env!("OPENVINO_BINDINGS_VERSION");
+
+/// Guards the one-time load of the OpenVINO shared libraries. OpenVINO's own plugin
+/// initialization can block inside `dlopen`/`__itt` global mutexes, so without this guard,
+/// concurrent first-use from several threads can deadlock or race into a partial init. Every
+/// caller, first or not, sees the same `Ok`/`Err` outcome of the single underlying load,
+/// including the specific [`LoadingError`] variant.
+static LOAD_RESULT: OnceLock<Result<(), LoadingError>> = OnceLock::new();
+
+/// Load the OpenVINO shared libraries.
+///
+/// This does not check that the loaded library's version matches the version this crate's
+/// bindings were generated against; prefer [`load_checked`] unless you know the loaded library is
+/// compatible despite a version difference.
+///
+/// Safe to call concurrently from multiple threads: the underlying load happens at most once, and
+/// every caller observes its result.
+pub fn load() -> Result<(), LoadingError> {
+    LOAD_RESULT.get_or_init(load_once).clone()
+}
+
+/// Perform the actual, unguarded load of the OpenVINO shared libraries.
+fn load_once() -> Result<(), LoadingError> {
+    let path = openvino_finder::find_library()
+        .map_err(|e| LoadingError::CannotFindLibraryPath(SearchDiagnostics::from(e)))?;
+    openvino_sys::load(&path).map_err(|e| LoadingError::SystemFailure(e.to_string()))
+}
+
+/// Load the OpenVINO shared libraries and verify that the loaded library's `MAJOR.MINOR` version
+/// matches [`BINDINGS_VERSION`], returning [`SetupError::VersionMismatch`] if not.
+pub fn load_checked() -> Result<(), SetupError> {
+    load()?;
+
+    let found = runtime_version()?;
+    let expected = BINDINGS_VERSION;
+    if major_minor(&found) != major_minor(expected) {
+        return Err(SetupError::VersionMismatch {
+            expected: expected.to_string(),
+            found,
+        });
+    }
+
+    Ok(())
+}
+
+/// Read the version string reported by the loaded OpenVINO library at runtime, checking the
+/// returned status before reading anything out of the (otherwise possibly unwritten) out-param.
+fn runtime_version() -> Result<String, SetupError> {
+    let mut version = std::mem::MaybeUninit::<openvino_sys::ov_version_t>::uninit();
+    let status = unsafe { openvino_sys::ov_get_openvino_version(version.as_mut_ptr()) };
+    InferenceError::from(status)?;
+
+    // Safe: `status == ov_status_e_OK` (checked above) guarantees OpenVINO wrote a valid
+    // `ov_version_t` into `version`.
+    let version = unsafe { version.assume_init() };
+    let description = unsafe {
+        std::ffi::CStr::from_ptr(version.buildNumber)
+            .to_string_lossy()
+            .into_owned()
+    };
+    unsafe { openvino_sys::ov_version_free(std::ptr::addr_of!(version).cast_mut()) };
+
+    Ok(description)
+}
+
+/// Extract the leading `MAJOR.MINOR` prefix from a version string like `2023.3.0-13775-abc`.
+fn major_minor(version: &str) -> &str {
+    let mut parts = version.splitn(3, '.');
+    let major = parts.next().unwrap_or_default();
+    let minor = parts.next().unwrap_or_default();
+    let len = major.len() + 1 + minor.len();
+    &version[..len.min(version.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::major_minor;
+
+    #[test]
+    fn extracts_major_minor_from_full_build_string() {
+        assert_eq!(major_minor("2023.3.0-13775-ceeafaf64f3-releases/2023/3"), "2023.3");
+    }
+
+    #[test]
+    fn extracts_major_minor_from_bare_major_minor() {
+        assert_eq!(major_minor("2023.3"), "2023.3");
+    }
+
+    #[test]
+    fn handles_missing_minor() {
+        assert_eq!(major_minor("2023"), "2023");
+    }
+
+    #[test]
+    fn handles_empty_string() {
+        assert_eq!(major_minor(""), "");
+    }
+}